@@ -0,0 +1,10 @@
+use syn::Ident;
+
+/// Results of the `check` pass, threaded through to codegen
+pub struct Extra {
+    /// Name the device crate was imported under
+    pub device: Ident,
+    /// Whether the target is ARMv6-M (no BASEPRI) and must lock resources by masking NVIC
+    /// interrupts instead of raising BASEPRI
+    pub ramv6m: bool,
+}
@@ -38,10 +38,7 @@ pub fn cfg_core(core: Core, cores: u8) -> Option<TokenStream2> {
 /// There may be more than one free queue per task because we need one for each sender core so we
 /// include the sender (e.g. `S0`) in the name
 pub fn fq_ident(task: &Ident, sender: Core) -> Ident {
-    Ident::new(
-        &format!("{}_S{}_FQ", task.to_string(), sender),
-        Span::call_site(),
-    )
+    mark_internal(&format!("{}_S{}_FQ", task.to_string(), sender))
 }
 
 /// Generates a `Mutex` implementation
@@ -61,7 +58,32 @@ pub fn impl_mutex(
         (quote!(#name), quote!(self.priority))
     };
 
-    let device = extra.device;
+    // ARMv6-M has no BASEPRI, so the Stack Resource Policy is enforced by masking exactly the
+    // interrupts at or below `ceiling` out of the NVIC (ICER/ISER) instead of raising BASEPRI
+    let body = if extra.ramv6m {
+        let masks = priority_masks_ident(ceiling);
+
+        quote!(
+            unsafe { rtfm::export::lock_mask(#ptr, &#masks, f) }
+        )
+    } else {
+        // `NVIC_PRIO_BITS` is resolved through the `rt_err_ident()` alias, not the device crate
+        // path directly, so an error resolving it names `__rtfm_needs_an_interrupt_here`
+        let rt_err = rt_err_ident();
+
+        quote!(
+            unsafe {
+                rtfm::export::lock(
+                    #ptr,
+                    #priority,
+                    CEILING,
+                    #rt_err::NVIC_PRIO_BITS,
+                    f,
+                )
+            }
+        )
+    };
+
     quote!(
         #(#cfgs)*
         #cfg_core
@@ -73,15 +95,7 @@ pub fn impl_mutex(
                 /// Priority ceiling
                 const CEILING: u8 = #ceiling;
 
-                unsafe {
-                    rtfm::export::lock(
-                        #ptr,
-                        #priority,
-                        CEILING,
-                        #device::NVIC_PRIO_BITS,
-                        f,
-                    )
-                }
+                #body
             }
         }
     )
@@ -89,17 +103,17 @@ pub fn impl_mutex(
 
 /// Generates an identifier for a cross-initialization barrier
 pub fn init_barrier(initializer: Core) -> Ident {
-    Ident::new(&format!("IB{}", initializer), Span::call_site())
+    mark_internal(&format!("IB{}", initializer))
 }
 
 /// Generates an identifier for the `INPUTS` buffer (`spawn` & `schedule` API)
 pub fn inputs_ident(task: &Ident, sender: Core) -> Ident {
-    Ident::new(&format!("{}_S{}_INPUTS", task, sender), Span::call_site())
+    mark_internal(&format!("{}_S{}_INPUTS", task, sender))
 }
 
 /// Generates an identifier for the `INSTANTS` buffer (`schedule` API)
 pub fn instants_ident(task: &Ident, sender: Core) -> Ident {
-    Ident::new(&format!("{}_S{}_INSTANTS", task, sender), Span::call_site())
+    mark_internal(&format!("{}_S{}_INSTANTS", task, sender))
 }
 
 /// Generates a pre-reexport identifier for the "late resources" struct
@@ -123,9 +137,31 @@ pub fn locals_ident(ctxt: Context, app: &App) -> Ident {
     Ident::new(&s, Span::call_site())
 }
 
+/// Mangles `name` behind the reserved internal prefix
+///
+/// All compiler-generated items that don't need to be nameable by the user (queues, barriers,
+/// buffers, etc.) must be routed through this function before being placed in the `const APP`
+/// scope. This keeps them collision-free with user-chosen names -- a task can be called `FQ` or
+/// `RV0` without shadowing the codegen.
+///
+/// This prefix alone does not hide these items from rustdoc/IDE completion -- that also needs
+/// `#[doc(hidden)]` attached at each generated item's declaration site in `codegen/mod.rs`, which
+/// is not part of this snapshot, so it isn't done here.
+pub fn mark_internal(name: &str) -> Ident {
+    Ident::new(&format!("__rtfm_internal_{}", name), Span::call_site())
+}
+
+/// Generates an identifier for the NVIC interrupt mask(s) of a priority ceiling
+///
+/// Names the `const` the analysis pass emits for `ceiling`, one `u32` word per 32 interrupts; read
+/// by `impl_mutex`'s ARMv6-M branch to feed `rtfm::export::lock_mask`.
+pub fn priority_masks_ident(ceiling: u8) -> Ident {
+    mark_internal(&format!("MASKS_P{}", ceiling))
+}
+
 /// Generates an identifier for a rendezvous barrier
 pub fn rendezvous_ident(core: Core) -> Ident {
-    Ident::new(&format!("RV{}", core), Span::call_site())
+    mark_internal(&format!("RV{}", core))
 }
 
 // Regroups the inputs of a task
@@ -196,10 +232,17 @@ pub fn resources_ident(ctxt: Context, app: &App) -> Ident {
 /// in turn may use more than one ready queue because the queues are SPSC queues so one is needed
 /// per sender core.
 pub fn rq_ident(receiver: Core, priority: u8, sender: Core) -> Ident {
-    Ident::new(
-        &format!("R{}_P{}_S{}_RQ", receiver, priority, sender),
-        Span::call_site(),
-    )
+    mark_internal(&format!("R{}_P{}_S{}_RQ", receiver, priority, sender))
+}
+
+/// Generates a descriptive alias for the device crate re-export
+///
+/// Device crate items resolved through this alias rather than the device crate path directly name
+/// this alias in the resulting error; in this file, `impl_mutex`'s BASEPRI path resolves
+/// `NVIC_PRIO_BITS` through it. The companion `use #device as #rt_err_ident()` import,
+/// replacing `use #device as _`, lives in `codegen/mod.rs`, not part of this snapshot.
+pub fn rt_err_ident() -> Ident {
+    Ident::new("__rtfm_needs_an_interrupt_here", Span::call_site())
 }
 
 /// Generates an identifier for a "schedule" function
@@ -207,20 +250,17 @@ pub fn rq_ident(receiver: Core, priority: u8, sender: Core) -> Ident {
 /// The methods of the `Schedule` structs invoke these functions. As one task may be `schedule`-ed
 /// by different cores we need one "schedule" function per possible task-sender pair
 pub fn schedule_ident(name: &Ident, sender: Core) -> Ident {
-    Ident::new(
-        &format!("schedule_{}_S{}", name.to_string(), sender),
-        Span::call_site(),
-    )
+    mark_internal(&format!("schedule_{}_S{}", name.to_string(), sender))
 }
 
 /// Generates an identifier for the `enum` of `schedule`-able tasks
 pub fn schedule_t_ident(core: Core) -> Ident {
-    Ident::new(&format!("T{}", core), Span::call_site())
+    mark_internal(&format!("T{}", core))
 }
 
 /// Generates an identifier for a cross-spawn barrier
 pub fn spawn_barrier(receiver: Core) -> Ident {
-    Ident::new(&format!("SB{}", receiver), Span::call_site())
+    mark_internal(&format!("SB{}", receiver))
 }
 
 /// Generates an identifier for a "spawn" function
@@ -228,10 +268,7 @@ pub fn spawn_barrier(receiver: Core) -> Ident {
 /// The methods of the `Spawn` structs invoke these functions. As one task may be `spawn`-ed by
 /// different cores we need one "spawn" function per possible task-sender pair
 pub fn spawn_ident(name: &Ident, sender: Core) -> Ident {
-    Ident::new(
-        &format!("spawn_{}_S{}", name.to_string(), sender),
-        Span::call_site(),
-    )
+    mark_internal(&format!("spawn_{}_S{}", name.to_string(), sender))
 }
 
 /// Generates an identifier for the `enum` of `spawn`-able tasks
@@ -239,15 +276,12 @@ pub fn spawn_ident(name: &Ident, sender: Core) -> Ident {
 /// This identifier needs the same structure as the `RQ` identifier because there's one ready queue
 /// for each of these `T` enums
 pub fn spawn_t_ident(receiver: Core, priority: u8, sender: Core) -> Ident {
-    Ident::new(
-        &format!("R{}_P{}_S{}_T", receiver, priority, sender),
-        Span::call_site(),
-    )
+    mark_internal(&format!("R{}_P{}_S{}_T", receiver, priority, sender))
 }
 
 /// Generates an identifier for a timer queue
 ///
 /// At most there's one timer queue per core
 pub fn tq_ident(core: Core) -> Ident {
-    Ident::new(&format!("TQ{}", core), Span::call_site())
+    mark_internal(&format!("TQ{}", core))
 }
\ No newline at end of file